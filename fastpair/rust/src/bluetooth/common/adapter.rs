@@ -0,0 +1,49 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+
+use super::{Device, ScanFilter, SignalStrengthFilter};
+
+/// Concrete types implementing this trait represent a platform's Bluetooth
+/// radio. They provide methods for scanning for nearby devices and
+/// retrieving them one at a time as they're discovered.
+#[async_trait]
+pub trait Adapter: Sized {
+    /// Platform-specific type representing a device discovered by this
+    /// adapter.
+    type Device: Device;
+
+    /// Retrieve the platform's default Bluetooth adapter.
+    async fn default() -> Result<Self, anyhow::Error>;
+
+    /// Starts scanning for nearby BLE advertisements. `signal_strength_filter`
+    /// and `scan_filter`, when provided, are handed down to the OS/radio so
+    /// only advertisements within range and/or matching the requested
+    /// service UUIDs/manufacturer data are reported, instead of every
+    /// advertisement in range being reported unconditionally and every
+    /// non-matching one having to be discarded after the fact.
+    fn start_scan(
+        &mut self,
+        signal_strength_filter: Option<&SignalStrengthFilter>,
+        scan_filter: Option<&ScanFilter>,
+    ) -> Result<(), anyhow::Error>;
+
+    /// Stops an ongoing scan started by `start_scan`.
+    fn stop_scan(&mut self) -> Result<(), anyhow::Error>;
+
+    /// Retrieve the next device discovered by the ongoing scan, waiting for
+    /// one to arrive if necessary.
+    async fn next_device(&mut self) -> Result<Self::Device, anyhow::Error>;
+}
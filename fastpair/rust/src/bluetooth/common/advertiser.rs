@@ -0,0 +1,39 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::{Arc, Mutex};
+
+use futures::channel::mpsc::{channel, Receiver, Sender};
+
+/// A status update reported by a BLE advertisement publisher as advertising
+/// starts, stops, or is aborted by the radio. Platform-independent; each
+/// backend maps its native publisher-status type into this one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublisherStatus {
+    Created,
+    Waiting,
+    Started,
+    Stopping,
+    Stopped,
+    Aborted,
+}
+
+/// Builds a channel for bridging a platform event-handler callback (which is
+/// only ever given a shared reference) into an async `Stream`. The sender is
+/// wrapped in an `Arc<Mutex<_>>` so it can be moved into a `'static`
+/// callback and still be reached from repeated invocations of that callback.
+pub fn status_changed_channel<T>() -> (Arc<Mutex<Sender<T>>>, Receiver<T>) {
+    let (sender, receiver) = channel(16);
+    (Arc::new(Mutex::new(sender)), receiver)
+}
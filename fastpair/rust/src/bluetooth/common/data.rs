@@ -12,10 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+// AD type bytes, as assigned by the Bluetooth SIG.
+// https://www.bluetooth.com/specifications/assigned-numbers/generic-access-profile/
 pub enum BleDataSection {
+    Flags = 0x01,
+    IncompleteServiceUuids16 = 0x02,
+    CompleteServiceUuids16 = 0x03,
+    IncompleteServiceUuids128 = 0x06,
+    CompleteServiceUuids128 = 0x07,
+    ShortenedLocalName = 0x08,
+    CompleteLocalName = 0x09,
+    TxPowerLevel = 0x0A,
     ServiceData16BitUUid = 0x16,
+    ServiceData128BitUUid = 0x21,
+    ManufacturerSpecificData = 0xFF,
 }
 
+#[derive(Clone)]
 pub struct ServiceData<Uuid: Copy> {
     uuid: Uuid,
     data: Vec<u8>,
@@ -34,3 +47,155 @@ impl<Uuid: Copy> ServiceData<Uuid> {
         &self.data
     }
 }
+
+/// Manufacturer-specific data section: a company identifier (assigned by the
+/// Bluetooth SIG) followed by arbitrary vendor-defined bytes.
+#[derive(Clone)]
+pub struct ManufacturerData {
+    company_id: u16,
+    data: Vec<u8>,
+}
+
+impl ManufacturerData {
+    pub fn new(company_id: u16, data: Vec<u8>) -> Self {
+        ManufacturerData { company_id, data }
+    }
+
+    pub fn get_company_id(&self) -> u16 {
+        self.company_id
+    }
+
+    pub fn get_data(&self) -> &Vec<u8> {
+        &self.data
+    }
+}
+
+/// The full set of AD structures parsed out of one advertisement payload.
+/// Fields are left empty/`None` when the advertiser didn't include that
+/// section, rather than treating it as an error.
+#[derive(Default, Clone)]
+pub struct AdvertisementData {
+    pub local_name: Option<String>,
+    pub flags: Option<u8>,
+    pub tx_power: Option<i8>,
+    pub service_uuids_16: Vec<u16>,
+    pub service_uuids_128: Vec<u128>,
+    pub service_data_16: Vec<ServiceData<u16>>,
+    pub service_data_128: Vec<ServiceData<u128>>,
+    pub manufacturer_data: Vec<ManufacturerData>,
+}
+
+/// A single observed advertisement: the signal strength it arrived at and
+/// the fully parsed AD structures it carried.
+#[derive(Default, Clone)]
+pub struct AdvertisementEvent {
+    pub rssi: Option<i16>,
+    pub data: AdvertisementData,
+}
+
+/// Restricts a scan to advertisements carrying one of `service_uuids_16`/
+/// `service_uuids_128` and/or a manufacturer data section matching
+/// `manufacturer_company_id`/`manufacturer_data_prefix`. Passed down to the
+/// OS/radio so non-matching packets are dropped before they ever reach this
+/// process, instead of every advertisement being pulled across the FFI
+/// boundary just to be filtered out in Rust. `manufacturer_data_prefix` is
+/// only applied when `manufacturer_company_id` is set; on its own it is
+/// ignored.
+#[derive(Default, Clone)]
+pub struct ScanFilter {
+    pub service_uuids_16: Vec<u16>,
+    pub service_uuids_128: Vec<u128>,
+    pub manufacturer_company_id: Option<u16>,
+    pub manufacturer_data_prefix: Option<Vec<u8>>,
+}
+
+/// Configures the OS-level RSSI filtering applied to an ongoing scan.
+/// Advertisements weaker than `in_range_threshold_dbm` are dropped before
+/// they ever reach this process; once a device that was in range goes
+/// unheard for `out_of_range_timeout`, a synthetic advertisement reporting
+/// `out_of_range_threshold_dbm` is emitted so callers can notice it leaving
+/// proximity instead of just seeing a flood of duplicate in-range events.
+#[derive(Clone)]
+pub struct SignalStrengthFilter {
+    pub in_range_threshold_dbm: i16,
+    pub out_of_range_threshold_dbm: i16,
+    pub out_of_range_timeout: std::time::Duration,
+    pub sampling_interval: std::time::Duration,
+}
+
+/// Parses the AD structures carried in one advertisement into an
+/// `AdvertisementData`. `sections` holds each structure's AD type byte
+/// paired with its data bytes, i.e. the `[type][data...]` that follows the
+/// `[len]` prefix of a `[len][type][data...]` TLV record; malformed or
+/// unrecognized sections are skipped rather than failing the whole parse.
+pub fn parse_advertisement_data(sections: &[(u8, Vec<u8>)]) -> AdvertisementData {
+    let mut parsed = AdvertisementData::default();
+
+    for (ad_type, data) in sections {
+        match *ad_type {
+            t if t == BleDataSection::Flags as u8 => {
+                parsed.flags = data.first().copied();
+            }
+            t if t == BleDataSection::ShortenedLocalName as u8
+                || t == BleDataSection::CompleteLocalName as u8 =>
+            {
+                parsed.local_name = String::from_utf8(data.clone()).ok();
+            }
+            t if t == BleDataSection::TxPowerLevel as u8 => {
+                parsed.tx_power = data.first().map(|byte| *byte as i8);
+            }
+            t if t == BleDataSection::IncompleteServiceUuids16 as u8
+                || t == BleDataSection::CompleteServiceUuids16 as u8 =>
+            {
+                parsed.service_uuids_16.extend(data.chunks_exact(2).map(|c| {
+                    u16::from_le_bytes([c[0], c[1]])
+                }));
+            }
+            t if t == BleDataSection::IncompleteServiceUuids128 as u8
+                || t == BleDataSection::CompleteServiceUuids128 as u8 =>
+            {
+                parsed
+                    .service_uuids_128
+                    .extend(data.chunks_exact(16).filter_map(|c| {
+                        Some(u128::from_le_bytes(c.try_into().ok()?))
+                    }));
+            }
+            t if t == BleDataSection::ServiceData16BitUUid as u8 => {
+                if data.len() >= 2 {
+                    let uuid = u16::from_le_bytes([data[0], data[1]]);
+                    parsed
+                        .service_data_16
+                        .push(ServiceData::new(uuid, data[2..].to_vec()));
+                }
+            }
+            t if t == BleDataSection::ServiceData128BitUUid as u8 => {
+                if data.len() >= 16 {
+                    if let Ok(uuid_bytes) = data[..16].try_into() {
+                        let uuid = u128::from_le_bytes(uuid_bytes);
+                        parsed
+                            .service_data_128
+                            .push(ServiceData::new(uuid, data[16..].to_vec()));
+                    }
+                }
+            }
+            t if t == BleDataSection::ManufacturerSpecificData as u8 => {
+                if data.len() >= 2 {
+                    let company_id = u16::from_le_bytes([data[0], data[1]]);
+                    parsed
+                        .manufacturer_data
+                        .push(ManufacturerData::new(company_id, data[2..].to_vec()));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    parsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // TODO b/288592509 unit tests
+}
@@ -14,19 +14,53 @@
 
 use async_trait::async_trait;
 
-use super::Address;
+use super::{Address, ServiceData};
+
+/// A stable identifier for a previously seen device, suitable for
+/// persisting across sessions (e.g. in application storage) and later
+/// reconnecting via `BleAdapter::device_from_id` without needing a fresh
+/// advertisement, unlike `Address`, which is only known once a device has
+/// actually been (re)discovered.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeviceId(String);
+
+impl DeviceId {
+    pub fn new(id: String) -> Self {
+        DeviceId(id)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
 
 /// Concrete types implementing this trait represent Bluetooth Peripheral devices.
 /// They provide methods for retrieving device info and running device actions,
 /// such as pairing.
 #[async_trait]
 pub trait Device: Sized {
+    /// Platform-specific type representing one of this device's discovered
+    /// GATT services.
+    type Service;
+
     /// Retrieve the name advertised by this device.
     fn name(&self) -> Result<String, anyhow::Error>;
 
     /// Retrieve this device's Bluetooth address information.
     fn address(&self) -> Address;
 
+    /// Retrieve this device's stable identifier.
+    fn id(&self) -> DeviceId;
+
     /// Attempt pairing with the peripheral device.
     async fn pair(&self) -> Result<(), anyhow::Error>;
+
+    /// Retrieve the 16-bit service data sections advertised by this device.
+    fn get_service_data(&self) -> &Vec<ServiceData<u16>>;
+
+    /// Opens a GATT connection to the device.
+    async fn connect(&self) -> Result<(), anyhow::Error>;
+
+    /// Enumerates the GATT services the connected device exposes.
+    async fn discover_services(&self) -> Result<Vec<Self::Service>, anyhow::Error>;
 }
@@ -15,11 +15,25 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use futures::{channel::mpsc::Receiver, StreamExt};
+use futures::{
+    channel::mpsc::{Receiver, Sender},
+    StreamExt,
+};
 use tracing::{error, info, warn};
 use windows::{
     Devices::Bluetooth::{
         Advertisement::{
+            // The advertisement payload a watcher's filter is matched
+            // against, and that a publisher would broadcast.
+            // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothleadvertisement?view=winrt-22621
+            BluetoothLEAdvertisement,
+
+            // Restricts a watcher to advertisements matching a given
+            // `BluetoothLEAdvertisement` template (e.g. specific service
+            // UUIDs) and/or byte patterns.
+            // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothleadvertisementfilter?view=winrt-22621
+            BluetoothLEAdvertisementFilter,
+
             // Struct that receives Bluetooth Low Energy (LE) advertisements.
             // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothleadvertisementwatcher?view=winrt-22621
             BluetoothLEAdvertisementReceivedEventArgs,
@@ -41,6 +55,11 @@ use windows::{
             // Defines constants that specify a Bluetooth LE scanning mode.
             // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothlescanningmode?view=winrt-22621
             BluetoothLEScanningMode,
+
+            // Restricts a watcher to reporting advertisements within an RSSI
+            // range, and to notify once a device goes unheard for a timeout.
+            // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothsignalstrengthfilter?view=winrt-22621
+            BluetoothSignalStrengthFilter,
         },
         // Struct for obtaining global constant information about a computer's
         // Bluetooth adapter.
@@ -50,19 +69,196 @@ use windows::{
     // Wraps a closure for handling events associated with a struct
     // (e.g. Received and Stopped events in BluetoothLEAdvertisementWatcher).
     // https://learn.microsoft.com/en-us/uwp/api/windows.foundation.typedeventhandler-2?view=winrt-22621
-    Foundation::TypedEventHandler,
+    Foundation::{TimeSpan, TypedEventHandler},
 };
 
 use super::BleDevice;
-use crate::bluetooth::common::{Adapter, BleAddress, BleAddressKind};
+use crate::bluetooth::common::{
+    parse_advertisement_data, Adapter, AdvertisementEvent, BleAddress, BleAddressKind, DeviceId,
+    ScanFilter, SignalStrengthFilter,
+};
+
+/// Converts a `std::time::Duration` into the `100`-nanosecond-tick
+/// `TimeSpan` WinRT APIs expect.
+fn duration_to_timespan(duration: std::time::Duration) -> TimeSpan {
+    TimeSpan {
+        Duration: (duration.as_nanos() / 100) as i64,
+    }
+}
+
+// The Bluetooth Base UUID that 16-bit service UUIDs are shorthand for.
+// https://www.bluetooth.com/specifications/assigned-numbers/generic-access-profile/
+const BLUETOOTH_BASE_UUID: u128 = 0x0000_0000_0000_1000_8000_00805F9B34FB;
+
+/// Expands a 16-bit service UUID into its full 128-bit form.
+fn guid_from_16bit_uuid(uuid: u16) -> windows::core::GUID {
+    windows::core::GUID::from_u128(BLUETOOTH_BASE_UUID | ((uuid as u128) << 96))
+}
+
+/// Reads the raw `[type][data...]` bytes out of one WinRT data section.
+fn read_section(
+    section: &windows::Devices::Bluetooth::Advertisement::BluetoothLEAdvertisementDataSection,
+) -> Option<(u8, Vec<u8>)> {
+    let ad_type = section.DataType().ok()?;
+    let buffer = section.Data().ok()?;
+    let reader = windows::Storage::Streams::DataReader::FromBuffer(&buffer).ok()?;
+    let len = reader.UnconsumedBufferLength().ok()? as usize;
+    let mut bytes = vec![0u8; len];
+    reader.ReadBytes(&mut bytes).ok()?;
+
+    Some((ad_type, bytes))
+}
+
+/// Platform-independent snapshot of one `Received` event, extracted from the
+/// WinRT event args as soon as they arrive. Everything downstream of the
+/// channel (`next_device`'s parsing loop) operates on this instead of the
+/// WinRT type, so it can be exercised by `FakeAdvertisementSource` in tests
+/// without a live Bluetooth radio.
+#[derive(Clone)]
+struct RawAdvertisement {
+    addr: BleAddress,
+    /// Mirrors `BluetoothLEAdvertisementType::NonConnectableUndirected`:
+    /// a beacon-style broadcast that can't be turned into a connectable
+    /// device.
+    non_connectable_undirected: bool,
+    rssi: Option<i16>,
+    sections: Vec<(u8, Vec<u8>)>,
+}
+
+/// Converts WinRT `Received` event args into a `RawAdvertisement`, doing all
+/// the WinRT-specific field extraction up front.
+fn raw_advertisement_from_event_args(
+    event_args: &BluetoothLEAdvertisementReceivedEventArgs,
+) -> Result<RawAdvertisement, anyhow::Error> {
+    let kind = BleAddressKind::try_from(event_args.BluetoothAddressType()?)?;
+    let addr = BleAddress::new(event_args.BluetoothAddress()?, kind);
+
+    let sections = event_args
+        .Advertisement()
+        .ok()
+        .and_then(|advertisement| advertisement.DataSections().ok())
+        .into_iter()
+        .flatten()
+        .filter_map(|section| read_section(&section))
+        .collect();
+
+    Ok(RawAdvertisement {
+        addr,
+        non_connectable_undirected: event_args.AdvertisementType()?
+            == BluetoothLEAdvertisementType::NonConnectableUndirected,
+        rssi: event_args.RawSignalStrengthInDBm().ok(),
+        sections,
+    })
+}
+
+/// Abstracts "produce a stream of `RawAdvertisement`s" so `next_device`'s
+/// address-parsing and skip logic can be unit-tested without a live
+/// Bluetooth radio. `WindowsAdvertisementSource` is the real implementation,
+/// backed by a `BluetoothLEAdvertisementWatcher`; tests use
+/// `FakeAdvertisementSource` to push synthetic events through the same
+/// `futures::mpsc` channel.
+trait AdvertisementSource {
+    /// Starts producing events into `sender`, closing it once the source
+    /// stops (mirroring a real watcher's `Stopped` event).
+    fn start(&self, sender: Sender<RawAdvertisement>) -> Result<(), anyhow::Error>;
+
+    /// Stops producing events.
+    fn stop(&self) -> Result<(), anyhow::Error>;
+}
+
+/// Real `AdvertisementSource`, backed by a `BluetoothLEAdvertisementWatcher`
+/// that's already been configured (scanning mode, filters) and is ready to
+/// `Start()`.
+struct WindowsAdvertisementSource {
+    watcher: BluetoothLEAdvertisementWatcher,
+}
+
+impl AdvertisementSource for WindowsAdvertisementSource {
+    fn start(&self, sender: Sender<RawAdvertisement>) -> Result<(), anyhow::Error> {
+        let sender = Arc::new(std::sync::Mutex::new(sender));
+
+        // `received_handler` closure holds non-owning channel reference, to
+        // ensure `stopped_handler` can close the channel when
+        // `received_handler` is done.
+        let weak_sender = Arc::downgrade(&sender);
+        let received_handler = TypedEventHandler::new(
+            // Move `weak_sender` into closure.
+            move |watcher: &Option<BluetoothLEAdvertisementWatcher>,
+                  event_args: &Option<
+                BluetoothLEAdvertisementReceivedEventArgs,
+            >| {
+                if watcher.is_some() {
+                    if let Some(event_args) = event_args {
+                        match raw_advertisement_from_event_args(event_args) {
+                            Ok(raw) => {
+                                if let Some(sender) = weak_sender.upgrade() {
+                                    if let Err(err) = sender.lock().unwrap().try_send(raw) {
+                                        error!("Error while handling Received event: {:?}", err)
+                                    }
+                                }
+                            }
+                            Err(err) => warn!("Error parsing received advertisement: {:?}", err),
+                        }
+                    }
+                }
+
+                Ok(())
+            },
+        );
+
+        // `stopped_handler` closure owns channel reference, can close channel.
+        let mut owned_sender = Some(sender);
+        let stopped_handler = TypedEventHandler::new(
+            // Move `owned_sender` into closure.
+            move |_watcher,
+                  _event_args: &Option<
+                BluetoothLEAdvertisementWatcherStoppedEventArgs,
+            >| {
+                // Drop `owned_sender`, closing the channel.
+                let _sender = owned_sender.take();
+                info!("Watcher stopped receiving BLE advertisements.");
+                Ok(())
+            },
+        );
+
+        self.watcher.Received(&received_handler)?;
+        self.watcher.Stopped(&stopped_handler)?;
+        self.watcher.Start()?;
+
+        Ok(())
+    }
+
+    fn stop(&self) -> Result<(), anyhow::Error> {
+        Ok(self.watcher.Stop()?)
+    }
+}
 
 /// Struct holding the necessary fields for listening to and handling incoming
 /// BLE advertisements.
 struct AdListener {
-    /// Holds callback for sending received advertisement events to `receiver`.
-    watcher: BluetoothLEAdvertisementWatcher,
+    /// Produces events into `receiver`; `Stopped` via `stop_scan`.
+    source: Box<dyn AdvertisementSource>,
     /// Can be polled to consume incoming advertisement events.
-    receiver: Receiver<BluetoothLEAdvertisementReceivedEventArgs>,
+    receiver: Receiver<RawAdvertisement>,
+}
+
+impl AdListener {
+    /// Pulls the next advertisement worth turning into a device, skipping
+    /// `NonConnectableUndirected` beacons along the way (they're broadcast-
+    /// only and can't be resolved into a connectable device).
+    async fn next_connectable(&mut self) -> Result<RawAdvertisement, anyhow::Error> {
+        loop {
+            let raw = self
+                .receiver
+                .next()
+                .await
+                .ok_or(anyhow::anyhow!("Event returned from stream is None."))?;
+
+            if !raw.non_connectable_undirected {
+                return Ok(raw);
+            }
+        }
+    }
 }
 
 /// Concrete type implementing `Adapter`, used for Windows BLE.
@@ -95,7 +291,11 @@ impl Adapter for BleAdapter {
         })
     }
 
-    fn start_scan(&mut self) -> Result<(), anyhow::Error> {
+    fn start_scan(
+        &mut self,
+        signal_strength_filter: Option<&SignalStrengthFilter>,
+        scan_filter: Option<&ScanFilter>,
+    ) -> Result<(), anyhow::Error> {
         let watcher = BluetoothLEAdvertisementWatcher::new()?;
         match watcher.SetScanningMode(BluetoothLEScanningMode::Active) {
             Ok(_) => (),
@@ -108,113 +308,298 @@ impl Adapter for BleAdapter {
             watcher.SetAllowExtendedAdvertisements(true)?;
         }
 
+        if let Some(filter) = signal_strength_filter {
+            let ss_filter = BluetoothSignalStrengthFilter::new()?;
+            ss_filter.SetInRangeThresholdInDBm(filter.in_range_threshold_dbm)?;
+            ss_filter.SetOutOfRangeThresholdInDBm(filter.out_of_range_threshold_dbm)?;
+            ss_filter.SetOutOfRangeTimeout(duration_to_timespan(filter.out_of_range_timeout))?;
+            ss_filter.SetSamplingInterval(duration_to_timespan(filter.sampling_interval))?;
+            watcher.SetSignalStrengthFilter(&ss_filter)?;
+        }
+
+        if let Some(filter) = scan_filter {
+            watcher.SetAdvertisementFilter(&build_advertisement_filter(filter)?)?;
+        }
+
         // `futures::channel::mpsc` is like `std::sync::mpsc` but `impl Stream`.
+        let (sender, receiver) = futures::channel::mpsc::channel(16);
+        let source = WindowsAdvertisementSource { watcher };
+        source.start(sender)?;
+
+        self.listener = Some(AdListener {
+            source: Box::new(source),
+            receiver,
+        });
+
+        Ok(())
+    }
+
+    fn stop_scan(&mut self) -> Result<(), anyhow::Error> {
+        if let Some(listener) = self.listener.take() {
+            listener.source.stop()
+        } else {
+            Err(anyhow::anyhow!("Device scanning hasn't started."))
+        }
+    }
+
+    async fn next_device(&mut self) -> Result<Self::Device, anyhow::Error> {
+        let listener = self
+            .listener
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("Device scanning hasn't started."))?;
+
+        // We don't want the end-user to receive empty devices, so this is a
+        // loop to catch and skip trivial errors from advertisements that
+        // can't be turned into devices.
+        loop {
+            let raw = listener.next_connectable().await?;
+
+            let advertisement_event = AdvertisementEvent {
+                rssi: raw.rssi,
+                data: parse_advertisement_data(&raw.sections),
+            };
+            let service_data = advertisement_event.data.service_data_16.clone();
+
+            match BleDevice::new(raw.addr, service_data).await {
+                Ok(mut device) => {
+                    device.set_advertisement_event(advertisement_event);
+                    return Ok(device);
+                }
+                Err(err) => {
+                    warn!("Error creating device: {:?}", err);
+                }
+            }
+        }
+    }
+}
+
+/// Builds the `BluetoothLEAdvertisementFilter` that restricts a watcher to
+/// advertisements matching `filter`'s service UUIDs and/or manufacturer data
+/// prefix.
+fn build_advertisement_filter(
+    filter: &ScanFilter,
+) -> Result<BluetoothLEAdvertisementFilter, anyhow::Error> {
+    let template = BluetoothLEAdvertisement::new()?;
+    let service_uuids = template.ServiceUuids()?;
+    for uuid in &filter.service_uuids_16 {
+        service_uuids.Append(guid_from_16bit_uuid(*uuid))?;
+    }
+    for uuid in &filter.service_uuids_128 {
+        service_uuids.Append(windows::core::GUID::from_u128(*uuid))?;
+    }
+
+    if let Some(company_id) = filter.manufacturer_company_id {
+        let manufacturer_data =
+            windows::Devices::Bluetooth::Advertisement::BluetoothLEManufacturerData::CreateWithCompanyIdAndData(
+                company_id,
+                &windows::Storage::Streams::Buffer::from(
+                    &filter.manufacturer_data_prefix.clone().unwrap_or_default()[..],
+                )?
+                .into(),
+            )?;
+        template.ManufacturerData()?.Append(&manufacturer_data)?;
+    }
+
+    let advertisement_filter = BluetoothLEAdvertisementFilter::new()?;
+    advertisement_filter.SetAdvertisement(&template)?;
+    Ok(advertisement_filter)
+}
+
+impl BleAdapter {
+    /// Re-resolves a previously seen device from its stable `DeviceId`,
+    /// without needing to wait for a fresh advertisement. Lets an
+    /// application store an id across sessions and reconnect to it later.
+    pub async fn device_from_id(&self, id: DeviceId) -> Result<BleDevice, anyhow::Error> {
+        BleDevice::from_id(id).await
+    }
+
+    /// Like `watch_advertisements`, but resolves `id` to its current
+    /// `BleAddress` first. Convenience for callers that persisted a
+    /// `DeviceId` rather than an address.
+    pub async fn watch_advertisements_by_id(
+        &self,
+        id: DeviceId,
+    ) -> Result<impl futures::stream::Stream<Item = AdvertisementEvent>, anyhow::Error> {
+        let addr = match BleDevice::from_id(id).await?.address() {
+            crate::bluetooth::common::Address::Ble(addr) => addr,
+            crate::bluetooth::common::Address::Classic(_) => {
+                return Err(anyhow::anyhow!(
+                    "DeviceId resolved to a Classic device, not BLE."
+                ))
+            }
+        };
+
+        self.watch_advertisements(addr)
+    }
+
+    /// A stream of advertisement/RSSI updates for one already known device,
+    /// identified directly by its `BleAddress`, rather than the
+    /// `next_device` firehose across every nearby advertiser. Supports
+    /// presence/telemetry use cases (tracking a specific beacon's signal and
+    /// changing payload over time) that the one-shot device-resolution loop
+    /// can't express.
+    pub fn watch_advertisements(
+        &self,
+        target_addr: BleAddress,
+    ) -> Result<impl futures::stream::Stream<Item = AdvertisementEvent>, anyhow::Error> {
+        let target_addr = crate::bluetooth::common::Address::Ble(target_addr);
+
+        let watcher = BluetoothLEAdvertisementWatcher::new()?;
+        watcher.SetScanningMode(BluetoothLEScanningMode::Active)?;
+        watcher.Start()?;
+
         let (sender, receiver) = futures::channel::mpsc::channel(16);
         let sender = Arc::new(std::sync::Mutex::new(sender));
 
-        // `received_handler` closure holds non-owning channel reference, to
-        // ensure `stopped_handler` can close the channel when
-        // `received_handler` is done.
         let weak_sender = Arc::downgrade(&sender);
         let received_handler = TypedEventHandler::new(
-            // Move `weak_sender` into closure.
-            move |watcher: &Option<BluetoothLEAdvertisementWatcher>,
-                  event_args: &Option<
-                BluetoothLEAdvertisementReceivedEventArgs,
-            >| {
-                if watcher.is_some() {
-                    if let Some(event_args) = event_args {
-                        if let Some(sender) = weak_sender.upgrade() {
-                            match sender
-                                .lock()
-                                .unwrap()
-                                .try_send(event_args.clone())
-                            {
-                                Ok(_) => (),
-                                Err(err) => {
-                                    error!("Error while handling Received event: {:?}", err)
-                                }
-                            }
-                        }
+            move |_watcher: &Option<BluetoothLEAdvertisementWatcher>,
+                  event_args: &Option<BluetoothLEAdvertisementReceivedEventArgs>| {
+                if let Some(event_args) = event_args {
+                    if let Some(sender) = weak_sender.upgrade() {
+                        let _ = sender.lock().unwrap().try_send(event_args.clone());
                     }
                 }
-
                 Ok(())
             },
         );
+        watcher.Received(&received_handler)?;
 
-        // `stopped_handler` closure owns channel reference, can close channel.
         let mut sender = Some(sender);
         let stopped_handler = TypedEventHandler::new(
-            // Move `sender` into closure.
-            move |_watcher,
-                  _event_args: &Option<
-                BluetoothLEAdvertisementWatcherStoppedEventArgs,
-            >| {
-                // Drop `sender`, closing the channel.
+            move |_watcher, _event_args: &Option<BluetoothLEAdvertisementWatcherStoppedEventArgs>| {
                 let _sender = sender.take();
-                info!("Watcher stopped receiving BLE advertisements.");
                 Ok(())
             },
         );
-
-        watcher.Received(&received_handler)?;
         watcher.Stopped(&stopped_handler)?;
-        watcher.Start()?;
 
-        self.listener = Some(AdListener { watcher, receiver });
+        Ok(Box::pin(receiver.filter_map(move |event_args| {
+            // Keep `watcher` alive for the lifetime of the stream.
+            let _watcher = &watcher;
+            let target_addr = target_addr.clone();
 
-        Ok(())
+            async move {
+                let addr = event_args.BluetoothAddress().ok()?;
+                let kind = event_args.BluetoothAddressType().ok()?;
+                let kind = BleAddressKind::try_from(kind).ok()?;
+                let addr = BleAddress::new(addr, kind);
+
+                // Only events for the watched device make it through;
+                // everyone else's advertisements are dropped here.
+                if crate::bluetooth::common::Address::Ble(addr) != target_addr {
+                    return None;
+                }
+
+                let sections: Vec<(u8, Vec<u8>)> = event_args
+                    .Advertisement()
+                    .ok()?
+                    .DataSections()
+                    .ok()?
+                    .into_iter()
+                    .filter_map(|section| read_section(&section))
+                    .collect();
+
+                Some(AdvertisementEvent {
+                    rssi: event_args.RawSignalStrengthInDBm().ok(),
+                    data: parse_advertisement_data(&sections),
+                })
+            }
+        })))
     }
+}
 
-    fn stop_scan(&mut self) -> Result<(), anyhow::Error> {
-        if let Some(listener) = self.listener.take() {
-            listener.watcher.Stop()?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test-only `AdvertisementSource` that lets a test push synthetic
+    /// `Received` events and simulate a `Stopped` event through the same
+    /// `futures::mpsc` channel `next_device` reads from, without a live
+    /// Bluetooth radio.
+    struct FakeAdvertisementSource {
+        sender: std::sync::Mutex<Option<Sender<RawAdvertisement>>>,
+    }
+
+    impl FakeAdvertisementSource {
+        fn new() -> Self {
+            FakeAdvertisementSource {
+                sender: std::sync::Mutex::new(None),
+            }
+        }
+
+        /// Simulates a `Received` event carrying `raw`.
+        fn push_received(&self, raw: RawAdvertisement) {
+            if let Some(sender) = self.sender.lock().unwrap().as_mut() {
+                let _ = sender.try_send(raw);
+            }
+        }
+    }
+
+    impl AdvertisementSource for FakeAdvertisementSource {
+        fn start(&self, sender: Sender<RawAdvertisement>) -> Result<(), anyhow::Error> {
+            *self.sender.lock().unwrap() = Some(sender);
+            Ok(())
+        }
+
+        fn stop(&self) -> Result<(), anyhow::Error> {
+            // Dropping the sender closes the channel, mirroring the real
+            // watcher's `Stopped` handler dropping its retained sender.
+            self.sender.lock().unwrap().take();
             Ok(())
-        } else {
-            Err(anyhow::anyhow!("Device scanning hasn't started."))
         }
     }
 
-    async fn next_device(&mut self) -> Result<Self::Device, anyhow::Error> {
-        if let Some(listener) = &mut self.listener {
-            let stream = &mut listener.receiver;
-            // We don't want the end-user to receive empty devices, so this is a
-            // loop to catch and skip trivial errors from advertisements that
-            // can't be turned into devices.
-            loop {
-                let event_args = stream.next().await.ok_or(anyhow::anyhow!(
-                    "Event returned from stream is None."
-                ))?;
-
-                match event_args.AdvertisementType()? {
-                    BluetoothLEAdvertisementType::NonConnectableUndirected => {
-                        ()
-                    }
-                    _ => {
-                        let kind = event_args.BluetoothAddressType()?;
-                        let addr = event_args.BluetoothAddress()?;
+    impl AdvertisementSource for Arc<FakeAdvertisementSource> {
+        fn start(&self, sender: Sender<RawAdvertisement>) -> Result<(), anyhow::Error> {
+            self.as_ref().start(sender)
+        }
 
-                        let kind = BleAddressKind::try_from(kind)?;
-                        let addr = BleAddress::new(addr, kind);
+        fn stop(&self) -> Result<(), anyhow::Error> {
+            self.as_ref().stop()
+        }
+    }
 
-                        match BleDevice::new(addr).await {
-                            Ok(device) => break Ok(device),
-                            Err(err) => {
-                                warn!("Error creating device: {:?}", err);
-                            }
-                        }
-                    }
-                }
-            }
-        } else {
-            Err(anyhow::anyhow!("Device scanning hasn't started."))
+    fn test_raw_advertisement(non_connectable_undirected: bool) -> RawAdvertisement {
+        RawAdvertisement {
+            addr: BleAddress::new(0x0011_2233_4455, BleAddressKind::Random),
+            non_connectable_undirected,
+            rssi: Some(-60),
+            sections: vec![(0x09, b"test-device".to_vec())],
         }
     }
-}
 
-mod tests {
-    use super::*;
+    fn listener_with_fake_source() -> (Arc<FakeAdvertisementSource>, AdListener) {
+        let fake = Arc::new(FakeAdvertisementSource::new());
+        let (sender, receiver) = futures::channel::mpsc::channel(16);
+        fake.start(sender).unwrap();
+
+        let listener = AdListener {
+            source: Box::new(fake.clone()),
+            receiver,
+        };
+        (fake, listener)
+    }
+
+    #[test]
+    fn next_connectable_skips_non_connectable_undirected() {
+        let (fake, mut listener) = listener_with_fake_source();
 
-    // TODO b/288592509 unit tests
+        fake.push_received(test_raw_advertisement(true));
+        fake.push_received(test_raw_advertisement(false));
+
+        let raw = futures::executor::block_on(listener.next_connectable()).unwrap();
+        assert!(!raw.non_connectable_undirected);
+        assert_eq!(raw.rssi, Some(-60));
+    }
+
+    #[test]
+    fn next_connectable_errors_once_source_is_stopped() {
+        let (fake, mut listener) = listener_with_fake_source();
+
+        fake.stop().unwrap();
+
+        let result = futures::executor::block_on(listener.next_connectable());
+        assert!(result.is_err());
+    }
 }
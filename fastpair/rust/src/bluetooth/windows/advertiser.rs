@@ -0,0 +1,130 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::stream::Stream;
+use windows::{
+    Devices::Bluetooth::Advertisement::{
+        // The advertisement payload a publisher broadcasts.
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothleadvertisement?view=winrt-22621
+        BluetoothLEAdvertisement,
+
+        // Manufacturer-specific data section attached to an advertisement.
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothlemanufacturerdata?view=winrt-22621
+        BluetoothLEManufacturerData,
+
+        // Broadcasts a `BluetoothLEAdvertisement` over the air.
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothleadvertisementpublisher?view=winrt-22621
+        BluetoothLEAdvertisementPublisher,
+
+        // Status of a `BluetoothLEAdvertisementPublisher` (Created, Waiting,
+        // Started, Stopping, Stopped, Aborted).
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothleadvertisementpublisherstatus?view=winrt-22621
+        BluetoothLEAdvertisementPublisherStatus,
+
+        // Provides data for a `StatusChanged` event on a publisher.
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothleadvertisementpublisherstatuschangedeventargs?view=winrt-22621
+        BluetoothLEAdvertisementPublisherStatusChangedEventArgs,
+    },
+    Foundation::TypedEventHandler,
+};
+
+use crate::bluetooth::common::{status_changed_channel, ManufacturerData, PublisherStatus};
+
+impl TryFrom<BluetoothLEAdvertisementPublisherStatus> for PublisherStatus {
+    type Error = anyhow::Error;
+
+    fn try_from(status: BluetoothLEAdvertisementPublisherStatus) -> Result<Self, Self::Error> {
+        match status {
+            BluetoothLEAdvertisementPublisherStatus::Created => Ok(PublisherStatus::Created),
+            BluetoothLEAdvertisementPublisherStatus::Waiting => Ok(PublisherStatus::Waiting),
+            BluetoothLEAdvertisementPublisherStatus::Started => Ok(PublisherStatus::Started),
+            BluetoothLEAdvertisementPublisherStatus::Stopping => Ok(PublisherStatus::Stopping),
+            BluetoothLEAdvertisementPublisherStatus::Stopped => Ok(PublisherStatus::Stopped),
+            BluetoothLEAdvertisementPublisherStatus::Aborted => Ok(PublisherStatus::Aborted),
+            _ => Err(anyhow::anyhow!(
+                "Received unrecognized publisher status: {:?}",
+                status
+            )),
+        }
+    }
+}
+
+/// Wraps `BluetoothLEAdvertisementPublisher` to let the crate broadcast BLE
+/// advertisements in the peripheral role, mirroring how `BleAdapter` wraps a
+/// watcher for the central/scanning role. Only manufacturer-specific data is
+/// reliably settable via the publisher on Windows, so this is the only
+/// section the constructor exposes.
+pub struct BleAdvertiser {
+    inner: BluetoothLEAdvertisementPublisher,
+}
+
+impl BleAdvertiser {
+    /// Builds a publisher broadcasting `manufacturer_data`.
+    pub fn with_manufacturer_data(
+        manufacturer_data: ManufacturerData,
+    ) -> Result<Self, anyhow::Error> {
+        let advertisement = BluetoothLEAdvertisement::new()?;
+
+        let section = BluetoothLEManufacturerData::CreateWithCompanyIdAndData(
+            manufacturer_data.get_company_id(),
+            &windows::Storage::Streams::Buffer::from(&manufacturer_data.get_data()[..])?.into(),
+        )?;
+        advertisement.ManufacturerData()?.Append(&section)?;
+
+        let inner = BluetoothLEAdvertisementPublisher::Create(&advertisement)?;
+
+        Ok(BleAdvertiser { inner })
+    }
+
+    /// Begins broadcasting. The radio may not start immediately; watch
+    /// `status_changed` for the `Started`/`Aborted` transition.
+    pub fn start(&self) -> Result<(), anyhow::Error> {
+        Ok(self.inner.Start()?)
+    }
+
+    /// Stops broadcasting.
+    pub fn stop(&self) -> Result<(), anyhow::Error> {
+        Ok(self.inner.Stop()?)
+    }
+
+    /// A stream of publisher status changes (`Started`/`Stopped`/`Aborted`/
+    /// etc), so callers can tell when advertising actually took effect or
+    /// was aborted by the radio.
+    pub fn status_changed(&self) -> Result<impl Stream<Item = PublisherStatus>, anyhow::Error> {
+        let (sender, receiver) = status_changed_channel();
+
+        let handler = TypedEventHandler::new(
+            move |_publisher: &Option<BluetoothLEAdvertisementPublisher>,
+                  event_args: &Option<BluetoothLEAdvertisementPublisherStatusChangedEventArgs>| {
+                if let Some(event_args) = event_args {
+                    if let Ok(status) = PublisherStatus::try_from(event_args.Status()?) {
+                        let _ = sender.lock().unwrap().try_send(status);
+                    }
+                }
+
+                Ok(())
+            },
+        );
+        self.inner.StatusChanged(&handler)?;
+
+        Ok(receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // TODO b/288592509 unit tests
+}
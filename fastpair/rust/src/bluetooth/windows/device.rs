@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use tracing::warn;
 use windows::{
@@ -37,16 +39,97 @@ use windows::{
             DevicePairingResultStatus,
         },
     },
+    core::HSTRING,
     Foundation::TypedEventHandler,
 };
 
-use crate::bluetooth::common::{Address, BleAddress, ClassicAddress, Device, ServiceData};
+use super::gatt::{check_gatt_status, Service};
+use crate::bluetooth::common::{
+    Address, AdvertisementEvent, BleAddress, BleAddressKind, ClassicAddress, Device, DeviceId,
+    ServiceData,
+};
+
+/// What the application wants to do about one round of a pairing ceremony:
+/// silently accept, accept while supplying a PIN (`ProvidePin`), or reject.
+pub enum PairingDecision {
+    Accept,
+    AcceptWithPin(String),
+    Reject,
+}
+
+/// Invoked synchronously from the `PairingRequested` handler with the
+/// ceremony kind Windows is asking about, and the PIN to display/confirm
+/// when one applies (`DisplayPin`/`ConfirmPinMatch`). Lets the application
+/// drive PIN entry and passkey confirmation instead of the ceremony only
+/// ever being able to auto-accept `ConfirmOnly`.
+pub type PairingCallback = dyn Fn(DevicePairingKinds, Option<String>) -> PairingDecision + Send + Sync;
+
+/// Default ceremony handler, preserving the crate's original behavior:
+/// silently accept `ConfirmOnly`, reject everything else.
+fn auto_confirm_only(kind: DevicePairingKinds, _pin: Option<String>) -> PairingDecision {
+    match kind {
+        DevicePairingKinds::ConfirmOnly => PairingDecision::Accept,
+        _ => {
+            warn!("Unsupported pairing kind {:?}", kind);
+            PairingDecision::Reject
+        }
+    }
+}
+
+/// Runs a custom pairing ceremony against `custom`, driving prompts through
+/// `on_pairing_requested`. Shared by `BleDevice::pair` and
+/// `ClassicDevice::pair` so the two stay in sync.
+async fn run_custom_pairing(
+    custom: DeviceInformationCustomPairing,
+    on_pairing_requested: Arc<PairingCallback>,
+) -> Result<(), anyhow::Error> {
+    custom.PairingRequested(&TypedEventHandler::new(
+        move |_custom: &Option<DeviceInformationCustomPairing>,
+              event_args: &Option<DevicePairingRequestedEventArgs>| {
+            let Some(event_args) = event_args else {
+                warn!("Empty pairing event arguments");
+                return Ok(());
+            };
+
+            let kind = event_args.PairingKind()?;
+            let pin = event_args.Pin().ok().map(|pin| pin.to_string_lossy());
+
+            match on_pairing_requested(kind, pin) {
+                PairingDecision::Accept => event_args.Accept(),
+                PairingDecision::AcceptWithPin(pin) => {
+                    event_args.AcceptWithPin(&windows::core::HSTRING::from(pin))
+                }
+                PairingDecision::Reject => Ok(()),
+            }
+        },
+    ))?;
+
+    let res = custom
+        .PairAsync(
+            DevicePairingKinds::ConfirmOnly
+                | DevicePairingKinds::ProvidePin
+                | DevicePairingKinds::ConfirmPinMatch
+                | DevicePairingKinds::DisplayPin,
+        )?
+        .await?;
+    let status = res.Status()?;
+
+    match status {
+        DevicePairingResultStatus::Paired | DevicePairingResultStatus::AlreadyPaired => Ok(()),
+        _ => Err(anyhow::anyhow!("Error while pairing: {:?}", status)),
+    }
+}
 
 /// Concrete type implementing `Device`, used for Windows BLE.
 pub struct BleDevice {
     inner: BluetoothLEDevice,
     addr: BleAddress,
-    service_data: Vec<ServiceData<u16>>
+    id: DeviceId,
+    service_data: Vec<ServiceData<u16>>,
+    /// RSSI and fully parsed AD structures from the advertisement that led
+    /// to this device being resolved (or, for `from_id`, left at its
+    /// default since no advertisement triggered the resolution).
+    advertisement_event: AdvertisementEvent,
 }
 
 /// Concrete type implementing `Device`, used for Windows Bluetooth Classic.
@@ -66,12 +149,56 @@ impl BleDevice {
         )?
         .await?;
 
-        Ok(BleDevice { inner, addr, service_data })
+        Self::from_inner(inner, addr, service_data)
+    }
+
+    /// Re-resolves a previously seen device from its stable `DeviceId`,
+    /// without needing a fresh advertisement.
+    pub async fn from_id(id: DeviceId) -> Result<Self, anyhow::Error> {
+        let inner =
+            BluetoothLEDevice::FromIdAsync(&HSTRING::from(id.as_str()))?.await?;
+
+        let raw_addr = inner.BluetoothAddress()?;
+        let kind = BleAddressKind::try_from(inner.BluetoothAddressType()?)?;
+        let addr = BleAddress::new(raw_addr, kind);
+
+        Self::from_inner(inner, addr, Vec::new())
+    }
+
+    fn from_inner(
+        inner: BluetoothLEDevice,
+        addr: BleAddress,
+        service_data: Vec<ServiceData<u16>>,
+    ) -> Result<Self, anyhow::Error> {
+        let id = DeviceId::new(inner.DeviceId()?.to_string_lossy());
+
+        Ok(BleDevice {
+            inner,
+            addr,
+            id,
+            service_data,
+            advertisement_event: AdvertisementEvent::default(),
+        })
+    }
+
+    /// The RSSI and parsed AD structures from the advertisement this device
+    /// was resolved from, if any.
+    pub fn advertisement_event(&self) -> &AdvertisementEvent {
+        &self.advertisement_event
+    }
+
+    /// Attaches the advertisement that led to this device being resolved.
+    /// Called by `BleAdapter::next_device` once it's parsed the full
+    /// payload, rather than discarding everything but the address.
+    pub(crate) fn set_advertisement_event(&mut self, event: AdvertisementEvent) {
+        self.advertisement_event = event;
     }
 }
 
 #[async_trait]
 impl Device for BleDevice {
+    type Service = Service;
+
     fn name(&self) -> Result<String, anyhow::Error> {
         Ok(self.inner.Name()?.to_string_lossy())
     }
@@ -80,17 +207,56 @@ impl Device for BleDevice {
         Address::Ble(self.addr)
     }
 
+    fn id(&self) -> DeviceId {
+        self.id.clone()
+    }
+
     async fn pair(&self) -> Result<(), anyhow::Error> {
-        // BLE Audio isn't supported on Windows natively, so devices can pair
-        // but don't playback. Might possibly work with UWP. Since the Classic
-        // and BLE APIs are very similar, it might be possible to copy-paste
-        // `ClassicDevice::pair` directly.
-        unimplemented!("BLE Pairing is currently unsupported.")
+        self.pair_with(Arc::new(auto_confirm_only)).await
     }
 
     fn get_service_data(&self) -> &Vec<ServiceData<u16>> {
         &self.service_data
     }
+
+    async fn connect(&self) -> Result<(), anyhow::Error> {
+        // `BluetoothLEDevice` connects lazily on first GATT request; issuing
+        // a services query forces the connection attempt to happen now so
+        // callers get an immediate error instead of one on first GATT call.
+        // The query can fail "successfully" too: an unreachable device is
+        // reported through `GattCommunicationStatus`, not the HRESULT, so
+        // `?` alone won't catch it.
+        let result = self.inner.GetGattServicesAsync()?.await?;
+        check_gatt_status(result.Status()?)
+    }
+
+    async fn discover_services(&self) -> Result<Vec<Service>, anyhow::Error> {
+        let result = self.inner.GetGattServicesAsync()?.await?;
+        check_gatt_status(result.Status()?)?;
+        Ok(result.Services()?.into_iter().map(Service::new).collect())
+    }
+}
+
+impl BleDevice {
+    /// Pairs with the device, driving the pairing ceremony through
+    /// `on_pairing_requested` instead of only auto-accepting `ConfirmOnly`.
+    /// Required for real Fast Pair passkey pairing, which relies on
+    /// `ConfirmPinMatch`/`DisplayPin`.
+    pub async fn pair_with(
+        &self,
+        on_pairing_requested: Arc<PairingCallback>,
+    ) -> Result<(), anyhow::Error> {
+        let pair_info = self.inner.DeviceInformation()?.Pairing()?;
+        if pair_info.IsPaired()? {
+            println!("Device already paired");
+            Ok(())
+        } else if !pair_info.CanPair()? {
+            println!("Device can't pair");
+            Ok(())
+        } else {
+            run_custom_pairing(pair_info.Custom()?, on_pairing_requested).await
+        }
+    }
 }
 
 
@@ -110,6 +276,10 @@ impl ClassicDevice {
 
 #[async_trait]
 impl Device for ClassicDevice {
+    // Classic Bluetooth uses SDP/RFCOMM, not GATT, so there's no `Service`
+    // type to speak of; `connect`/`discover_services` are unimplemented.
+    type Service = ();
+
     fn name(&self) -> Result<String, anyhow::Error> {
         Ok(self.inner.Name()?.to_string_lossy())
     }
@@ -118,6 +288,15 @@ impl Device for ClassicDevice {
         Address::Classic(self.addr)
     }
 
+    fn id(&self) -> DeviceId {
+        DeviceId::new(
+            self.inner
+                .DeviceId()
+                .map(|id| id.to_string_lossy())
+                .unwrap_or_default(),
+        )
+    }
+
     async fn pair(&self) -> Result<(), anyhow::Error> {
         let pair_info = self.inner.DeviceInformation()?.Pairing()?;
         if pair_info.IsPaired()? {
@@ -126,54 +305,25 @@ impl Device for ClassicDevice {
         } else if !pair_info.CanPair()? {
             println!("Device can't pair");
             Ok(())
-        } else {  
-            let custom = pair_info.Custom()?;
-            custom.PairingRequested(&TypedEventHandler::new(
-                |_custom: &Option<DeviceInformationCustomPairing>, 
-                event_args: &Option<DevicePairingRequestedEventArgs>,
-                |  {
-                    if let Some(event_args) = event_args {
-                        match event_args.PairingKind()? {
-                            DevicePairingKinds::ConfirmOnly => {
-                                event_args.Accept()                            
-                            }
-                            _ => {
-                                warn!("Unsupported pairing kind {:?}", event_args.PairingKind());
-                                Ok(())
-                            }
-                        }
-                    } else {
-                        warn!("Empty pairing event arguments");
-                        Ok(())
-                    }
-
-                },
-            ))?;
-            let res = custom
-                .PairAsync(
-                    DevicePairingKinds::ConfirmOnly
-                        | DevicePairingKinds::ProvidePin
-                        | DevicePairingKinds::ConfirmPinMatch
-                        | DevicePairingKinds::DisplayPin,
-                )?
-                .await?;
-            let status = res.Status()?;
-
-            match status {
-                DevicePairingResultStatus::Paired
-                | DevicePairingResultStatus::AlreadyPaired => {
-                    Ok(())
-                }
-                _ => Err(anyhow::anyhow!("Error while pairing: {:?}", status)),
-            }
+        } else {
+            run_custom_pairing(pair_info.Custom()?, Arc::new(auto_confirm_only)).await
         }
     }
 
     fn get_service_data(&self) -> &Vec<ServiceData<u16>> {
         unimplemented!("Service data is currently unsupported for Classic devices.")
     }
+
+    async fn connect(&self) -> Result<(), anyhow::Error> {
+        unimplemented!("GATT is unsupported for Classic devices.")
+    }
+
+    async fn discover_services(&self) -> Result<Vec<()>, anyhow::Error> {
+        unimplemented!("GATT is unsupported for Classic devices.")
+    }
 }
 
+#[cfg(test)]
 mod tests {
     use super::*;
 
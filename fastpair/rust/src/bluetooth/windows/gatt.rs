@@ -0,0 +1,174 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use futures::stream::Stream;
+use windows::{
+    core::GUID,
+    Devices::Bluetooth::GenericAttributeProfile::{
+        // A single GATT service exposed by a connected device.
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.genericattributeprofile.gattdeviceservice?view=winrt-22621
+        GattDeviceService,
+
+        // A single characteristic within a `GattDeviceService`.
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.genericattributeprofile.gattcharacteristic?view=winrt-22621
+        GattCharacteristic,
+
+        // The client characteristic configuration value (Notify/Indicate/None).
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.genericattributeprofile.gattclientcharacteristicconfigurationdescriptorvalue?view=winrt-22621
+        GattClientCharacteristicConfigurationDescriptorValue,
+
+        // Whether a GATT operation succeeded, and if not, how it failed
+        // (unreachable device, protocol error, access denied). Reported
+        // out-of-band from the HRESULT, so callers must check it explicitly
+        // rather than relying on `?` alone.
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.genericattributeprofile.gattcommunicationstatus?view=winrt-22621
+        GattCommunicationStatus,
+
+        // Data for a `ValueChanged` event on a `GattCharacteristic`.
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.genericattributeprofile.gattvaluechangedeventargs?view=winrt-22621
+        GattValueChangedEventArgs,
+
+        // Write mode for `WriteValueAsync` (with or without response).
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.genericattributeprofile.gattwriteoption?view=winrt-22621
+        GattWriteOption,
+    },
+    Foundation::TypedEventHandler,
+    Storage::Streams::{DataReader, DataWriter},
+};
+
+/// Maps a non-`Success` `GattCommunicationStatus` to an `Err`.
+/// `GattCommunicationStatus` is reported out-of-band from the HRESULT (e.g.
+/// an unreachable device still returns `Ok` from the WinRT call), so every
+/// GATT operation needs to check this explicitly rather than relying on `?`
+/// alone.
+pub(crate) fn check_gatt_status(status: GattCommunicationStatus) -> Result<(), anyhow::Error> {
+    match status {
+        GattCommunicationStatus::Success => Ok(()),
+        _ => Err(anyhow::anyhow!("GATT operation failed: {:?}", status)),
+    }
+}
+
+/// One GATT service discovered on a connected device. Obtained from
+/// `Device::discover_services`.
+pub struct Service {
+    inner: GattDeviceService,
+}
+
+impl Service {
+    pub(crate) fn new(inner: GattDeviceService) -> Self {
+        Service { inner }
+    }
+
+    pub fn uuid(&self) -> Result<GUID, anyhow::Error> {
+        Ok(self.inner.Uuid()?)
+    }
+
+    /// Enumerates this service's characteristics.
+    pub async fn characteristics(&self) -> Result<Vec<Characteristic>, anyhow::Error> {
+        let result = self.inner.GetCharacteristicsAsync()?.await?;
+        check_gatt_status(result.Status()?)?;
+
+        Ok(result
+            .Characteristics()?
+            .into_iter()
+            .map(Characteristic::new)
+            .collect())
+    }
+}
+
+/// One characteristic of a `Service`, supporting read/write/notify, the
+/// three operations needed to drive a GATT-based pairing handshake (e.g.
+/// Fast Pair's key-based pairing characteristic).
+pub struct Characteristic {
+    inner: GattCharacteristic,
+}
+
+impl Characteristic {
+    fn new(inner: GattCharacteristic) -> Self {
+        Characteristic { inner }
+    }
+
+    pub fn uuid(&self) -> Result<GUID, anyhow::Error> {
+        Ok(self.inner.Uuid()?)
+    }
+
+    /// Reads the characteristic's current value.
+    pub async fn read(&self) -> Result<Vec<u8>, anyhow::Error> {
+        let result = self.inner.ReadValueAsync()?.await?;
+        check_gatt_status(result.Status()?)?;
+        buffer_to_vec(&result.Value()?)
+    }
+
+    /// Writes `data` to the characteristic, requesting a response from the
+    /// peripheral.
+    pub async fn write(&self, data: &[u8]) -> Result<(), anyhow::Error> {
+        let writer = DataWriter::new()?;
+        writer.WriteBytes(data)?;
+        let buffer = writer.DetachBuffer()?;
+
+        let status = self
+            .inner
+            .WriteValueWithOptionAsync(&buffer, GattWriteOption::WriteWithResponse)?
+            .await?;
+        check_gatt_status(status)
+    }
+
+    /// Subscribes to value-change notifications, returning a stream of each
+    /// notified payload. Writes `Notify` to the characteristic's client
+    /// configuration descriptor on every call.
+    pub async fn notifications(&self) -> Result<impl Stream<Item = Vec<u8>>, anyhow::Error> {
+        let status = self
+            .inner
+            .WriteClientCharacteristicConfigurationDescriptorAsync(
+                GattClientCharacteristicConfigurationDescriptorValue::Notify,
+            )?
+            .await?;
+        check_gatt_status(status)?;
+
+        let (sender, receiver) = futures::channel::mpsc::channel(16);
+        let sender = Arc::new(std::sync::Mutex::new(sender));
+
+        let handler = TypedEventHandler::new(
+            move |_characteristic: &Option<GattCharacteristic>,
+                  event_args: &Option<GattValueChangedEventArgs>| {
+                if let Some(event_args) = event_args {
+                    if let Ok(bytes) = buffer_to_vec(&event_args.CharacteristicValue()?) {
+                        let _ = sender.lock().unwrap().try_send(bytes);
+                    }
+                }
+                Ok(())
+            },
+        );
+        self.inner.ValueChanged(&handler)?;
+
+        Ok(receiver)
+    }
+}
+
+/// Copies a WinRT `IBuffer` into an owned `Vec<u8>`.
+fn buffer_to_vec(buffer: &windows::Storage::Streams::IBuffer) -> Result<Vec<u8>, anyhow::Error> {
+    let reader = DataReader::FromBuffer(buffer)?;
+    let mut bytes = vec![0u8; reader.UnconsumedBufferLength()? as usize];
+    reader.ReadBytes(&mut bytes)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // TODO b/288592509 unit tests
+}
@@ -13,13 +13,25 @@
 // limitations under the License.
 use super::BleDevice;
 
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 
 use futures::{stream::Stream, StreamExt};
 use tracing::{error, warn};
 use windows::{
     Devices::Bluetooth::{
         Advertisement::{
+            // Single length-type-value advertising data structure (e.g. TX
+            // Power Level) carried inside a `BluetoothLEAdvertisement`.
+            // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothleadvertisementdatasection?view=winrt-22621
+            BluetoothLEAdvertisementDataSection,
+
+            // The advertisement payload itself, made up of `DataSections`.
+            // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothleadvertisement?view=winrt-22621
+            BluetoothLEAdvertisement,
+
             // Struct that receives Bluetooth Low Energy (LE) advertisements.
             // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothleadvertisementwatcher?view=winrt-22621
             BluetoothLEAdvertisementReceivedEventArgs,
@@ -52,6 +64,35 @@ use windows::{
     Foundation::TypedEventHandler,
 };
 
+use crate::bluetooth::common::{parse_advertisement_data, AdvertisementData};
+
+/// Reads the raw `[type][data...]` bytes out of one WinRT data section.
+fn read_section(section: &BluetoothLEAdvertisementDataSection) -> Option<(u8, Vec<u8>)> {
+    let ad_type = section.DataType().ok()?;
+    let buffer = section.Data().ok()?;
+    let reader = windows::Storage::Streams::DataReader::FromBuffer(&buffer).ok()?;
+    let len = reader.UnconsumedBufferLength().ok()? as usize;
+    let mut bytes = vec![0u8; len];
+    reader.ReadBytes(&mut bytes).ok()?;
+
+    Some((ad_type, bytes))
+}
+
+/// Parses every AD structure out of an advertisement into an
+/// `AdvertisementData`, dropping any section WinRT failed to hand back
+/// cleanly rather than failing the whole advertisement.
+fn parse_advertisement(advertisement: &BluetoothLEAdvertisement) -> AdvertisementData {
+    let sections: Vec<(u8, Vec<u8>)> = advertisement
+        .DataSections()
+        .ok()
+        .into_iter()
+        .flatten()
+        .filter_map(|section| read_section(&section))
+        .collect();
+
+    parse_advertisement_data(&sections)
+}
+
 pub struct BleAdapter {
     inner: BluetoothAdapter,
 }
@@ -125,6 +166,12 @@ impl BleAdapter {
         watcher.Stopped(&stopped_handler)?;
         watcher.Start()?;
 
+        // Caches resolved `BleDevice`s by address so a device re-seen across
+        // multiple advertisements gets its RSSI/TX-power refreshed in place
+        // rather than being resolved all over again. The refreshed device is
+        // still yielded by the stream for every advertisement it sends.
+        let devices = Arc::new(Mutex::new(HashMap::new()));
+
         // `receiver` is a `futures::channel::mpsc::Receiver`, which implements
         // `futures::stream::Stream`. This is essentially an async Iterator. We apply a FilterMap to
         // map from advertisement packet to a future returning `BleDevice` and filter out undesired
@@ -133,6 +180,7 @@ impl BleAdapter {
             //  Move `watcher` into `FilterMap` closure. This ensures `watcher` is only dropped when
             // the stream is closed.
             let _watcher = &watcher;
+            let devices = devices.clone();
 
             // Move `event_args` into async block.
             async move {
@@ -141,9 +189,27 @@ impl BleAdapter {
                     _ => {
                         let addr = event_args.BluetoothAddress().ok()?;
                         let kind = event_args.BluetoothAddressType().ok()?;
+                        let rssi = event_args.RawSignalStrengthInDBm().ok();
+                        let advertisement_data = event_args
+                            .Advertisement()
+                            .ok()
+                            .map(|advertisement| parse_advertisement(&advertisement))
+                            .unwrap_or_default();
+                        let tx_power = advertisement_data.tx_power;
+
+                        if let Some(device) = devices.lock().unwrap().get_mut(&addr) {
+                            device.update_signal(rssi, tx_power);
+                            device.update_advertisement_data(advertisement_data);
+                            return Some(device.clone());
+                        }
 
                         match BleDevice::from_addr(addr, kind).await {
-                            Ok(device) => Some(device),
+                            Ok(mut device) => {
+                                device.update_signal(rssi, tx_power);
+                                device.update_advertisement_data(advertisement_data);
+                                devices.lock().unwrap().insert(addr, device.clone());
+                                Some(device)
+                            }
                             Err(err) => {
                                 warn!("Error creating device: {:?}", err);
                                 None
@@ -156,6 +222,7 @@ impl BleAdapter {
     }
 }
 
+#[cfg(test)]
 mod tests {
     use super::*;
 
@@ -0,0 +1,145 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::stream::Stream;
+use windows::{
+    Devices::Bluetooth::Advertisement::{
+        // The advertisement payload a publisher broadcasts.
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothleadvertisement?view=winrt-22621
+        BluetoothLEAdvertisement,
+
+        // Manufacturer-specific data section attached to an advertisement.
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothlemanufacturerdata?view=winrt-22621
+        BluetoothLEManufacturerData,
+
+        // Broadcasts a `BluetoothLEAdvertisement` over the air.
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothleadvertisementpublisher?view=winrt-22621
+        BluetoothLEAdvertisementPublisher,
+
+        // Provides data for a `StatusChanged` event on a publisher.
+        // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.advertisement.bluetoothleadvertisementpublisherstatuschangedeventargs?view=winrt-22621
+        BluetoothLEAdvertisementPublisherStatusChangedEventArgs,
+    },
+    Foundation::TypedEventHandler,
+};
+
+use crate::bluetooth::common::{status_changed_channel, ManufacturerData, PublisherStatus, ServiceData};
+
+/// Whether a broadcast advertisement can be connected to. Windows only
+/// exposes this as a property of the publisher's anonymity setting: a
+/// connectable peripheral role (central-initiated GATT connections) still
+/// requires a `GattServiceProvider`, which is out of scope for a bare
+/// advertisement broadcast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdvertisingMode {
+    /// Broadcast-only; the device's real Bluetooth address isn't included,
+    /// matching a beacon that can't be connected to.
+    NonConnectable,
+    /// Includes the device's real Bluetooth address so a central can
+    /// initiate a connection after observing the advertisement.
+    Connectable,
+}
+
+// `TryFrom<BluetoothLEAdvertisementPublisherStatus> for PublisherStatus` lives
+// in `windows::advertiser` (there's only one `PublisherStatus` now that it's
+// shared via `common`, and Rust only permits one impl of a trait for a given
+// type crate-wide); it applies here too since trait impls aren't module-scoped.
+
+/// Wraps `BluetoothLEAdvertisementPublisher` to let the crate broadcast BLE
+/// advertisements in the peripheral role, mirroring how `BleAdapter` wraps a
+/// watcher for the central/scanning role.
+pub struct BleAdvertiser {
+    inner: BluetoothLEAdvertisementPublisher,
+}
+
+impl BleAdvertiser {
+    /// Builds a publisher broadcasting `manufacturer_data`, the only section
+    /// type `BluetoothLEAdvertisementPublisher` reliably honors on Windows.
+    pub fn with_manufacturer_data(
+        manufacturer_data: ManufacturerData,
+        mode: AdvertisingMode,
+    ) -> Result<Self, anyhow::Error> {
+        let advertisement = BluetoothLEAdvertisement::new()?;
+
+        let section = BluetoothLEManufacturerData::CreateWithCompanyIdAndData(
+            manufacturer_data.get_company_id(),
+            &windows::Storage::Streams::Buffer::from(&manufacturer_data.get_data()[..])?.into(),
+        )?;
+        advertisement.ManufacturerData()?.Append(&section)?;
+
+        let inner = BluetoothLEAdvertisementPublisher::Create(&advertisement)?;
+        inner.SetIsAnonymous(mode == AdvertisingMode::NonConnectable)?;
+
+        Ok(BleAdvertiser { inner })
+    }
+
+    /// Builds a publisher broadcasting the given 16-bit service data section.
+    ///
+    /// `BluetoothLEAdvertisementPublisher` only reliably honors
+    /// manufacturer-specific data sections on Windows; setting a service-data
+    /// section (or most other reserved AD types) drives the publisher to
+    /// `Aborted` at `start()` instead of broadcasting, so this is rejected up
+    /// front rather than handed to the radio to fail silently later. Use
+    /// `with_manufacturer_data` for the section type Windows does support.
+    pub fn with_service_data(
+        _service_data: ServiceData<u16>,
+        _mode: AdvertisingMode,
+    ) -> Result<Self, anyhow::Error> {
+        Err(anyhow::anyhow!(
+            "Broadcasting a service-data section is not supported: the \
+            Windows BLE publisher only reliably honors manufacturer-specific \
+            data and aborts advertising for other section types."
+        ))
+    }
+
+    /// Begins broadcasting. The radio may not start immediately; watch
+    /// `status_changed` for the `Started`/`Aborted` transition.
+    pub fn start(&self) -> Result<(), anyhow::Error> {
+        Ok(self.inner.Start()?)
+    }
+
+    /// Stops broadcasting.
+    pub fn stop(&self) -> Result<(), anyhow::Error> {
+        Ok(self.inner.Stop()?)
+    }
+
+    /// A stream of publisher status changes (`Started`/`Stopped`/`Aborted`/
+    /// etc), so callers can tell when advertising actually took effect.
+    pub fn status_changed(&self) -> Result<impl Stream<Item = PublisherStatus>, anyhow::Error> {
+        let (sender, receiver) = status_changed_channel();
+
+        let handler = TypedEventHandler::new(
+            move |_publisher: &Option<BluetoothLEAdvertisementPublisher>,
+                  event_args: &Option<BluetoothLEAdvertisementPublisherStatusChangedEventArgs>| {
+                if let Some(event_args) = event_args {
+                    if let Ok(status) = PublisherStatus::try_from(event_args.Status()?) {
+                        let _ = sender.lock().unwrap().try_send(status);
+                    }
+                }
+
+                Ok(())
+            },
+        );
+        self.inner.StatusChanged(&handler)?;
+
+        Ok(receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // TODO b/288592509 unit tests
+}
@@ -0,0 +1,123 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use windows::Devices::Bluetooth::{
+    // Enum describing the type of address (public, random, unspecified).
+    // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.bluetoothaddresstype?view=winrt-22621
+    BluetoothAddressType,
+    // Struct for interacting with and pairing to a discovered BLE device.
+    // https://learn.microsoft.com/en-us/uwp/api/windows.devices.bluetooth.bluetoothledevice?view=winrt-22621
+    BluetoothLEDevice,
+};
+
+use crate::bluetooth::common::AdvertisementData;
+
+/// A discovered BLE peripheral. Resolved from the raw address/address-type
+/// pair carried by a `Received` advertisement event.
+#[derive(Clone)]
+pub struct BleDevice {
+    inner: BluetoothLEDevice,
+    addr: u64,
+    kind: BluetoothAddressType,
+    rssi: Option<i16>,
+    tx_power: Option<i8>,
+    advertisement_data: AdvertisementData,
+}
+
+impl BleDevice {
+    /// Resolves a `BleDevice` from a raw Bluetooth address and address kind.
+    pub async fn from_addr(
+        addr: u64,
+        kind: BluetoothAddressType,
+    ) -> Result<Self, anyhow::Error> {
+        let inner = BluetoothLEDevice::FromBluetoothAddressWithBluetoothAddressTypeAsync(
+            addr, kind,
+        )?
+        .await?;
+
+        Ok(BleDevice {
+            inner,
+            addr,
+            kind,
+            rssi: None,
+            tx_power: None,
+            advertisement_data: AdvertisementData::default(),
+        })
+    }
+
+    pub fn name(&self) -> Result<String, anyhow::Error> {
+        Ok(self.inner.Name()?.to_string_lossy())
+    }
+
+    pub fn address(&self) -> u64 {
+        self.addr
+    }
+
+    pub fn kind(&self) -> BluetoothAddressType {
+        self.kind
+    }
+
+    /// The most recently observed received signal strength, in dBm.
+    pub fn rssi(&self) -> Option<i16> {
+        self.rssi
+    }
+
+    /// The TX power level advertised by the device, in dBm, if present.
+    pub fn tx_power(&self) -> Option<i8> {
+        self.tx_power
+    }
+
+    /// A rough distance estimate, in meters, derived from `rssi` and
+    /// `tx_power` via the standard log-distance path loss model. Returns
+    /// `None` if either value hasn't been observed yet.
+    pub fn estimated_distance_m(&self) -> Option<f64> {
+        let rssi = self.rssi? as f64;
+        let tx_power = self.tx_power? as f64;
+
+        Some(10f64.powf((tx_power - rssi) / 20.0))
+    }
+
+    /// Updates the most recently observed signal information for this
+    /// device. The scanner calls this every time a new advertisement is
+    /// seen for an address that's already been resolved into a `BleDevice`,
+    /// so RSSI reflects the latest packet rather than the first.
+    pub(crate) fn update_signal(&mut self, rssi: Option<i16>, tx_power: Option<i8>) {
+        if rssi.is_some() {
+            self.rssi = rssi;
+        }
+        if tx_power.is_some() {
+            self.tx_power = tx_power;
+        }
+    }
+
+    /// The fully parsed AD structures (manufacturer data, flags, local name,
+    /// service UUIDs/data) from the most recently observed advertisement.
+    pub fn advertisement_data(&self) -> &AdvertisementData {
+        &self.advertisement_data
+    }
+
+    /// Replaces the cached advertisement data with a freshly parsed payload.
+    /// Called by the scanner every time a new advertisement is seen.
+    pub(crate) fn update_advertisement_data(&mut self, data: AdvertisementData) {
+        self.advertisement_data = data;
+    }
+
+    pub fn pair(&self) -> Result<(), anyhow::Error> {
+        // BLE Audio isn't supported on Windows natively, so devices can pair
+        // but don't playback. Might possibly work with UWP. Since the Classic
+        // and BLE APIs are very similar, it might be possible to copy-paste
+        // `ClassicDevice::pair` directly.
+        unimplemented!("BLE Pairing is currently unsupported.")
+    }
+}
@@ -81,7 +81,7 @@ async fn get_user_input(
 fn main() -> Result<(), anyhow::Error> {
     let run = async {
         let mut adapter = bluetooth::default_adapter().await?;
-        adapter.start_scan()?;
+        adapter.start_scan(None, None)?;
 
         let mut addr_set = HashSet::new();
         let device_vec = Arc::new(Mutex::new(Vec::new()));